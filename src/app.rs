@@ -1,11 +1,14 @@
 use crate::world::World;
 use crate::{
-    camera_controller::CameraController, frame_timer::FrameTimer, gui::GuiParams,
-    light_controller::LightController, renderer::Renderer,
+    camera_controller::CameraController,
+    frame_timer::FrameTimer,
+    gui::GuiParams,
+    input::{ActionHandler, AxisAction, DigitalAction},
+    light_controller::LightController,
+    renderer::Renderer,
 };
 use std::{cell::RefCell, rc::Rc, time::Duration};
-use winit::event::{DeviceEvent, ElementState, MouseButton, WindowEvent};
-use winit::keyboard::{KeyCode, PhysicalKey};
+use winit::event::{DeviceEvent, WindowEvent};
 use winit::window::Window;
 
 pub enum WindowEventHandlingResult {
@@ -20,6 +23,7 @@ pub struct App {
     pub frame_timer: FrameTimer,
     _gui_params: Rc<RefCell<GuiParams>>,
     world: World,
+    action_handler: ActionHandler,
 }
 
 impl App {
@@ -40,6 +44,7 @@ impl App {
             frame_timer,
             _gui_params: gui_params,
             world,
+            action_handler: ActionHandler::new(),
         }
     }
 
@@ -73,34 +78,43 @@ impl App {
             },
         );
 
-        self.camera_controller.process_device_events(event);
+        self.camera_controller.process_device_events(event.clone());
+        self.action_handler.process_device_event(&event);
+
+        let (delta_x, delta_y) = self.action_handler.take_look_delta();
+        if delta_x != 0.0 || delta_y != 0.0 {
+            self.camera_controller.process_look_input(delta_x, delta_y);
+        }
     }
 
     pub fn handle_window_event(&mut self, event: WindowEvent) -> WindowEventHandlingResult {
+        self.action_handler.process_window_event(&event);
+
         match event {
             WindowEvent::CloseRequested => return WindowEventHandlingResult::RequestExit,
 
-            WindowEvent::KeyboardInput { event, .. } => {
-                if event.state == ElementState::Pressed
-                    && event.physical_key == PhysicalKey::Code(KeyCode::KeyF)
-                {
-                    self.renderer.toggle_should_draw_gui();
-                }
-            }
-
             WindowEvent::Resized(new_size) => {
                 self.resize(new_size);
             }
             WindowEvent::ScaleFactorChanged { scale_factor, .. } => {
                 // self.resize(inner_size_writer); // TODO Handle scale factor change
             }
-            WindowEvent::MouseInput { state, button, .. } if button == MouseButton::Right => {
-                self.camera_controller
-                    .set_is_movement_enabled(state == ElementState::Pressed);
-            }
             _ => {}
         };
 
+        if self.action_handler.take_just_pressed(DigitalAction::ToggleGui) {
+            self.renderer.toggle_should_draw_gui();
+        }
+
+        if self.action_handler.take_just_pressed(DigitalAction::ToggleCamera) {
+            self.camera_controller.toggle_active_camera_kind();
+        }
+
+        self.camera_controller.set_is_movement_enabled(
+            self.action_handler
+                .is_held(DigitalAction::EnableCameraMovement),
+        );
+
         WindowEventHandlingResult::Handled
     }
 
@@ -120,6 +134,12 @@ impl App {
     }
 
     pub fn update(&mut self, delta: Duration) {
+        self.camera_controller.set_move_input(
+            self.action_handler.get_axis(AxisAction::MoveRightLeft),
+            self.action_handler.get_axis(AxisAction::MoveUpDown),
+            self.action_handler.get_axis(AxisAction::MoveForwardBack),
+        );
+
         self.camera_controller.update(delta, &self.renderer.queue);
 
         self.light_controller.update(delta, &self.renderer.queue);
@@ -1,7 +1,8 @@
 use cgmath::{
-    num_traits::clamp, ElementWise, Euler, InnerSpace, Point3, Quaternion, Rad, Rotation,
-    Rotation3, Vector2, Vector3, Zero,
+    num_traits::clamp, Euler, InnerSpace, Point3, Quaternion, Rad, Rotation, Rotation3, Vector2,
+    Vector3, Zero,
 };
+use std::f32::consts::LN_2;
 use std::f32::consts::PI;
 use std::time::Duration;
 use winit::event::*;
@@ -16,11 +17,13 @@ pub const OPENGL_TO_WGPU_MATRIX: cgmath::Matrix4<f32> = cgmath::Matrix4::new(
 
 const REFERENCE_DIRECTION: Vector3<f32> = Vector3::new(1.0, 0.0, 0.0);
 
-const CAMERA_UP_VECTOR: Vector3<f32> = Vector3::new(0 as f32, 1 as f32, 0 as f32);
+pub(crate) const CAMERA_UP_VECTOR: Vector3<f32> = Vector3::new(0 as f32, 1 as f32, 0 as f32);
 
-const MOVEMENT_SENSITIVITY: f32 = 20.0;
 const MOUSE_LOOK_SENSITIVITY: f32 = 0.005;
 
+const DEFAULT_THRUST_MAG: f32 = 40.0;
+const DEFAULT_HALF_LIFE: f32 = 0.15;
+
 #[repr(C)]
 #[derive(Debug, Copy, Clone, bytemuck::Pod, bytemuck::Zeroable)]
 pub struct CameraRaw {
@@ -28,7 +31,27 @@ pub struct CameraRaw {
     camera_pos: [f32; 4],
 }
 
-pub struct Camera {
+/// Lets `CameraController` hold any camera behavior behind a `Box<dyn Camera>`.
+pub trait Camera {
+    fn get_vp(&self) -> [[f32; 4]; 4];
+    fn get_position(&self) -> Point3<f32>;
+    fn resize(&mut self, aspect: f32);
+    fn update(&mut self, dt: Duration);
+    fn process_device_events(&mut self, _event: DeviceEvent) {}
+    fn process_look_input(&mut self, _delta_x: f32, _delta_y: f32) {}
+    fn set_move_input(&mut self, _right_left: f32, _up_down: f32, _forward_back: f32) {}
+    fn set_is_rotation_enabled(&mut self, _is_enabled: bool) {}
+    fn stop_movement(&mut self) {}
+
+    fn to_raw(&self) -> CameraRaw {
+        CameraRaw {
+            view_proj: self.get_vp(),
+            camera_pos: self.get_position().to_homogeneous().into(),
+        }
+    }
+}
+
+pub struct FlyCamera {
     eye: cgmath::Point3<f32>,
     up: Vector3<f32>,
     aspect: f32,
@@ -37,14 +60,20 @@ pub struct Camera {
     zfar: f32,
     look_sensitivity: Vector2<f32>,
     orientation: Euler<cgmath::Rad<f32>>,
-    current_speed_positive: Vector3<f32>,
-    current_speed_negative: Vector3<f32>,
-    movement_sensitivity: Vector3<f32>,
+    move_input: Vector3<f32>,
+    velocity: Vector3<f32>,
+    thrust_mag: f32,
+    damping_coeff: f32,
     is_rotation_enabled: bool,
 }
 
-impl Camera {
+impl FlyCamera {
     pub fn new(aspect_ratio: f32) -> Self {
+        Self::new_with_flight_params(aspect_ratio, DEFAULT_THRUST_MAG, DEFAULT_HALF_LIFE)
+    }
+
+    /// `half_life` is the time in seconds for velocity to halve once thrust stops.
+    pub fn new_with_flight_params(aspect_ratio: f32, thrust_mag: f32, half_life: f32) -> Self {
         let eye: Point3<f32> = (-12.0, 10.0, 0.0).into();
         let target: Point3<f32> = (0.0, 0.0, 0.0).into();
         let view_dir = (target - eye).normalize();
@@ -64,30 +93,14 @@ impl Camera {
             zfar: 100.0,
             orientation,
             look_sensitivity: cgmath::Vector2::new(MOUSE_LOOK_SENSITIVITY, MOUSE_LOOK_SENSITIVITY),
-            movement_sensitivity: Vector3::new(
-                MOVEMENT_SENSITIVITY,
-                MOVEMENT_SENSITIVITY,
-                MOVEMENT_SENSITIVITY,
-            ),
-            current_speed_positive: Vector3::<f32>::zero(),
-            current_speed_negative: Vector3::<f32>::zero(),
+            move_input: Vector3::<f32>::zero(),
+            velocity: Vector3::<f32>::zero(),
+            thrust_mag,
+            damping_coeff: LN_2 / half_life,
             is_rotation_enabled: false,
         }
     }
 
-    pub fn to_raw(&self) -> CameraRaw {
-        let view = cgmath::Matrix4::look_at_rh(self.eye, self.get_target(), self.up);
-        let proj = cgmath::perspective(self.fovy, self.aspect, self.znear, self.zfar);
-        return CameraRaw {
-            view_proj: (OPENGL_TO_WGPU_MATRIX * proj * view).into(),
-            camera_pos: self.get_position().to_homogeneous().into(),
-        };
-    }
-
-    pub fn get_position(&self) -> cgmath::Point3<f32> {
-        self.eye
-    }
-
     pub fn get_forward(&self) -> Vector3<f32> {
         let pitch_rotation = Quaternion::from_angle_y(self.orientation.x);
         let yaw_rotation = Quaternion::from_angle_z(self.orientation.z);
@@ -102,83 +115,66 @@ impl Camera {
         self.eye + self.get_forward()
     }
 
-    pub fn resize(&mut self, aspect: f32) {
+    fn rotate(&mut self, (delta_x, delta_y): (f32, f32)) {
+        self.orientation.x += Rad(self.look_sensitivity.x * -delta_x);
+        self.orientation.z += Rad(self.look_sensitivity.y * -delta_y);
+        self.orientation.z = clamp(
+            self.orientation.z,
+            Rad(-PI / 2.0 + 0.0001),
+            Rad(PI / 2.0 - 0.0001),
+        );
+    }
+}
+
+impl Camera for FlyCamera {
+    fn get_vp(&self) -> [[f32; 4]; 4] {
+        let view = cgmath::Matrix4::look_at_rh(self.eye, self.get_target(), self.up);
+        let proj = cgmath::perspective(self.fovy, self.aspect, self.znear, self.zfar);
+        (OPENGL_TO_WGPU_MATRIX * proj * view).into()
+    }
+
+    fn get_position(&self) -> cgmath::Point3<f32> {
+        self.eye
+    }
+
+    fn resize(&mut self, aspect: f32) {
         self.aspect = aspect;
     }
 
-    pub fn set_is_camera_rotation_enabled(&mut self, is_enabled: bool) {
+    fn set_is_rotation_enabled(&mut self, is_enabled: bool) {
         self.is_rotation_enabled = is_enabled;
     }
 
-    fn handle_keyboard_event(&mut self, keyboard_event: &KeyboardInput) {
-        match keyboard_event.state {
-            ElementState::Pressed => {
-                if let Some(keycode) = keyboard_event.virtual_keycode {
-                    match keycode {
-                        VirtualKeyCode::W => self.current_speed_positive.z = 1.0,
-                        VirtualKeyCode::S => self.current_speed_negative.z = 1.0,
-                        VirtualKeyCode::A => self.current_speed_negative.x = 1.0,
-                        VirtualKeyCode::D => self.current_speed_positive.x = 1.0,
-                        VirtualKeyCode::Q => self.current_speed_positive.y = 1.0,
-                        VirtualKeyCode::E => self.current_speed_negative.y = 1.0,
-                        _ => (),
-                    }
-                }
-            }
-            ElementState::Released => {
-                if let Some(keycode) = keyboard_event.virtual_keycode {
-                    match keycode {
-                        VirtualKeyCode::W => self.current_speed_positive.z = 0.0,
-                        VirtualKeyCode::S => self.current_speed_negative.z = 0.0,
-                        VirtualKeyCode::A => self.current_speed_negative.x = 0.0,
-                        VirtualKeyCode::D => self.current_speed_positive.x = 0.0,
-                        VirtualKeyCode::Q => self.current_speed_positive.y = 0.0,
-                        VirtualKeyCode::E => self.current_speed_negative.y = 0.0,
-                        _ => (),
-                    }
-                }
-            }
-        }
+    fn set_move_input(&mut self, right_left: f32, up_down: f32, forward_back: f32) {
+        self.move_input = Vector3::new(right_left, up_down, forward_back);
     }
 
-    pub fn process_device_events(&mut self, event: DeviceEvent) {
-        match event {
-            DeviceEvent::MouseMotion { delta } => {
-                if self.is_rotation_enabled {
-                    self.rotate((delta.0 as f32, delta.1 as f32));
-                }
-            }
-            DeviceEvent::Key(keyboard_input) => {
-                self.handle_keyboard_event(&keyboard_input);
-            }
-            _ => (),
+    fn process_look_input(&mut self, delta_x: f32, delta_y: f32) {
+        if self.is_rotation_enabled {
+            self.rotate((delta_x, delta_y));
         }
     }
 
-    pub fn update(&mut self, delta: Duration) {
-        let current_speed = self.current_speed_positive - self.current_speed_negative;
-        if current_speed.is_zero() {
-            return;
-        }
+    fn update(&mut self, delta: Duration) {
+        let dt = delta.as_secs_f32();
 
-        let speed_norm = current_speed.normalize();
-        let right = speed_norm.x * self.get_right();
-        let up = speed_norm.y * CAMERA_UP_VECTOR;
-        let forward = speed_norm.z * self.get_forward();
+        let thrust = if self.move_input.is_zero() {
+            Vector3::<f32>::zero()
+        } else {
+            let input_norm = self.move_input.normalize();
+            let right = input_norm.x * self.get_right();
+            let up = input_norm.y * CAMERA_UP_VECTOR;
+            let forward = input_norm.z * self.get_forward();
 
-        let v = delta.as_secs_f32()
-            * (right + up + forward).mul_element_wise(self.movement_sensitivity);
+            (right + up + forward) * self.thrust_mag
+        };
 
-        self.eye += v;
+        self.velocity += (thrust - self.velocity * self.damping_coeff) * dt;
+        self.eye += self.velocity * dt;
     }
 
-    fn rotate(&mut self, (delta_x, delta_y): (f32, f32)) {
-        self.orientation.x += Rad(self.look_sensitivity.x * -delta_x);
-        self.orientation.z += Rad(self.look_sensitivity.y * -delta_y);
-        self.orientation.z = clamp(
-            self.orientation.z,
-            Rad(-PI / 2.0 + 0.0001),
-            Rad(PI / 2.0 - 0.0001),
-        );
+    fn stop_movement(&mut self) {
+        self.move_input = Vector3::<f32>::zero();
+        self.velocity = Vector3::<f32>::zero();
     }
 }
@@ -1,13 +1,23 @@
-use glam::{Mat4, Vec4};
 use std::time;
 use wgpu::util::DeviceExt;
 use winit::event::DeviceEvent;
 
-use crate::camera::Camera;
+use crate::camera::{Camera, CameraRaw, FlyCamera};
+use crate::orbit_camera::OrbitCamera;
+
+/// Which concrete [`Camera`] implementation is currently driving the view. Lets users switch
+/// between flying through the scene and orbiting a fixed point at runtime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CameraKind {
+    FlyCam,
+    Orbit,
+}
 
 /// Contains the rendering-related concepts of the camera
 pub struct CameraController {
-    camera: Camera,
+    camera: Box<dyn Camera>,
+    active_kind: CameraKind,
+    aspect: f32,
     pub binding_buffer: wgpu::Buffer,
     pub bind_group: wgpu::BindGroup,
     is_movement_enabled: bool,
@@ -19,11 +29,11 @@ impl CameraController {
         render_device: &wgpu::Device,
         camera_bind_group_layout: &wgpu::BindGroupLayout,
     ) -> CameraController {
-        let camera = Camera::new(aspect_ratio);
+        let camera: Box<dyn Camera> = Box::new(FlyCamera::new(aspect_ratio));
 
         let binding_buffer = render_device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
             label: Some("Camera Buffer"),
-            contents: bytemuck::cast_slice(&[Self::get_raw(&camera)]),
+            contents: bytemuck::cast_slice(&[camera.to_raw()]),
             usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
         });
 
@@ -38,6 +48,8 @@ impl CameraController {
 
         Self {
             camera,
+            active_kind: CameraKind::FlyCam,
+            aspect: aspect_ratio,
             binding_buffer,
             bind_group,
             is_movement_enabled: false,
@@ -45,6 +57,7 @@ impl CameraController {
     }
 
     pub fn resize(&mut self, aspect: f32) {
+        self.aspect = aspect;
         self.camera.resize(aspect);
     }
 
@@ -58,8 +71,18 @@ impl CameraController {
         );
     }
 
+    pub fn set_move_input(&mut self, right_left: f32, up_down: f32, forward_back: f32) {
+        if !self.is_movement_enabled {
+            return;
+        }
+
+        self.camera
+            .set_move_input(right_left, up_down, forward_back);
+    }
+
     pub fn set_is_movement_enabled(&mut self, value: bool) {
         self.is_movement_enabled = value;
+        self.camera.set_is_rotation_enabled(value);
 
         if !self.is_movement_enabled {
             self.camera.stop_movement();
@@ -72,27 +95,33 @@ impl CameraController {
         }
     }
 
-    pub fn to_raw(&self) -> CameraRaw {
-        Self::get_raw(&self.camera)
-    }
+    /// Switches the active camera behavior, e.g. from a gui toggle, recreating the new
+    /// camera fresh at the controller's current aspect ratio.
+    pub fn set_active_camera_kind(&mut self, kind: CameraKind) {
+        if kind == self.active_kind {
+            return;
+        }
 
-    fn get_raw(camera: &Camera) -> CameraRaw {
-        let view = Mat4::look_at_rh(camera.position, camera.get_target(), camera.up);
-        let proj = Mat4::perspective_rh(camera.fovy, camera.aspect, camera.znear, camera.zfar);
+        self.camera = match kind {
+            CameraKind::FlyCam => Box::new(FlyCamera::new(self.aspect)),
+            CameraKind::Orbit => Box::new(OrbitCamera::new(self.aspect)),
+        };
+        self.active_kind = kind;
+    }
 
-        let pos = camera.get_position();
-        let pos_homogenous = Vec4::new(pos.x, pos.y, pos.z, 1.0_f32);
+    pub fn toggle_active_camera_kind(&mut self) {
+        let next = match self.active_kind {
+            CameraKind::FlyCam => CameraKind::Orbit,
+            CameraKind::Orbit => CameraKind::FlyCam,
+        };
+        self.set_active_camera_kind(next);
+    }
 
-        CameraRaw {
-            view_proj: (proj * view).to_cols_array_2d(),
-            camera_pos: pos_homogenous.to_array(),
-        }
+    pub fn active_camera_kind(&self) -> CameraKind {
+        self.active_kind
     }
-}
 
-#[repr(C)]
-#[derive(Debug, Copy, Clone, bytemuck::Pod, bytemuck::Zeroable)]
-pub struct CameraRaw {
-    view_proj: [[f32; 4]; 4],
-    camera_pos: [f32; 4],
+    pub fn to_raw(&self) -> CameraRaw {
+        self.camera.to_raw()
+    }
 }
@@ -0,0 +1,173 @@
+use std::collections::HashMap;
+
+use winit::event::{DeviceEvent, ElementState, MouseButton, WindowEvent};
+use winit::keyboard::{KeyCode, PhysicalKey};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AxisAction {
+    MoveForwardBack,
+    MoveRightLeft,
+    MoveUpDown,
+    LookYaw,
+    LookPitch,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DigitalAction {
+    ToggleGui,
+    EnableCameraMovement,
+    ToggleCamera,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum AxisDirection {
+    Positive,
+    Negative,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct AxisState {
+    positive: f32,
+    negative: f32,
+}
+
+impl AxisState {
+    fn value(&self) -> f32 {
+        self.positive - self.negative
+    }
+}
+
+/// Maps physical keys/mouse buttons to named actions, so controls can be remapped at
+/// runtime instead of being hardcoded into match arms on `KeyCode`/`MouseButton`.
+pub struct ActionHandler {
+    axis_key_bindings: HashMap<KeyCode, (AxisAction, AxisDirection)>,
+    digital_key_bindings: HashMap<KeyCode, DigitalAction>,
+    digital_mouse_bindings: HashMap<MouseButton, DigitalAction>,
+    axis_states: HashMap<AxisAction, AxisState>,
+    digital_held: HashMap<DigitalAction, bool>,
+    digital_just_pressed: HashMap<DigitalAction, bool>,
+}
+
+impl ActionHandler {
+    pub fn new() -> Self {
+        let axis_key_bindings = HashMap::from([
+            (KeyCode::KeyW, (AxisAction::MoveForwardBack, AxisDirection::Positive)),
+            (KeyCode::KeyS, (AxisAction::MoveForwardBack, AxisDirection::Negative)),
+            (KeyCode::KeyD, (AxisAction::MoveRightLeft, AxisDirection::Positive)),
+            (KeyCode::KeyA, (AxisAction::MoveRightLeft, AxisDirection::Negative)),
+            (KeyCode::KeyQ, (AxisAction::MoveUpDown, AxisDirection::Positive)),
+            (KeyCode::KeyE, (AxisAction::MoveUpDown, AxisDirection::Negative)),
+        ]);
+
+        let digital_key_bindings = HashMap::from([
+            (KeyCode::KeyF, DigitalAction::ToggleGui),
+            (KeyCode::KeyC, DigitalAction::ToggleCamera),
+        ]);
+
+        let digital_mouse_bindings =
+            HashMap::from([(MouseButton::Right, DigitalAction::EnableCameraMovement)]);
+
+        Self {
+            axis_key_bindings,
+            digital_key_bindings,
+            digital_mouse_bindings,
+            axis_states: HashMap::new(),
+            digital_held: HashMap::new(),
+            digital_just_pressed: HashMap::new(),
+        }
+    }
+
+    pub fn bind_axis_key(&mut self, key: KeyCode, action: AxisAction, direction_is_positive: bool) {
+        let direction = if direction_is_positive {
+            AxisDirection::Positive
+        } else {
+            AxisDirection::Negative
+        };
+        self.axis_key_bindings.insert(key, (action, direction));
+    }
+
+    pub fn bind_digital_key(&mut self, key: KeyCode, action: DigitalAction) {
+        self.digital_key_bindings.insert(key, action);
+    }
+
+    pub fn get_axis(&self, action: AxisAction) -> f32 {
+        self.axis_states.get(&action).copied().unwrap_or_default().value()
+    }
+
+    pub fn is_held(&self, action: DigitalAction) -> bool {
+        self.digital_held.get(&action).copied().unwrap_or(false)
+    }
+
+    /// Consumes the press edge, so each press is only reported once.
+    pub fn take_just_pressed(&mut self, action: DigitalAction) -> bool {
+        self.digital_just_pressed.remove(&action).unwrap_or(false)
+    }
+
+    /// Consumes the accumulated `LookYaw`/`LookPitch` axes, resetting them to zero.
+    pub fn take_look_delta(&mut self) -> (f32, f32) {
+        let yaw = self
+            .axis_states
+            .remove(&AxisAction::LookYaw)
+            .map_or(0.0, |s| s.value());
+        let pitch = self
+            .axis_states
+            .remove(&AxisAction::LookPitch)
+            .map_or(0.0, |s| s.value());
+        (yaw, pitch)
+    }
+
+    pub fn process_device_event(&mut self, event: &DeviceEvent) {
+        match event {
+            DeviceEvent::Key(raw_key_event) => {
+                self.handle_key(raw_key_event.physical_key, raw_key_event.state);
+            }
+            DeviceEvent::MouseMotion { delta } => {
+                self.axis_states.entry(AxisAction::LookYaw).or_default().positive +=
+                    delta.0 as f32;
+                self.axis_states.entry(AxisAction::LookPitch).or_default().positive +=
+                    delta.1 as f32;
+            }
+            _ => (),
+        }
+    }
+
+    pub fn process_window_event(&mut self, event: &WindowEvent) {
+        match event {
+            WindowEvent::KeyboardInput { event, .. } => {
+                self.handle_key(event.physical_key, event.state);
+            }
+            WindowEvent::MouseInput { state, button, .. } => {
+                if let Some(&action) = self.digital_mouse_bindings.get(button) {
+                    self.set_digital_state(action, *state == ElementState::Pressed);
+                }
+            }
+            _ => (),
+        }
+    }
+
+    fn handle_key(&mut self, physical_key: PhysicalKey, state: ElementState) {
+        let PhysicalKey::Code(key_code) = physical_key else {
+            return;
+        };
+
+        if let Some(&(action, direction)) = self.axis_key_bindings.get(&key_code) {
+            let magnitude = if state == ElementState::Pressed { 1.0 } else { 0.0 };
+            let axis_state = self.axis_states.entry(action).or_default();
+            match direction {
+                AxisDirection::Positive => axis_state.positive = magnitude,
+                AxisDirection::Negative => axis_state.negative = magnitude,
+            }
+        }
+
+        if let Some(&action) = self.digital_key_bindings.get(&key_code) {
+            self.set_digital_state(action, state == ElementState::Pressed);
+        }
+    }
+
+    fn set_digital_state(&mut self, action: DigitalAction, is_pressed: bool) {
+        let was_held = self.digital_held.insert(action, is_pressed).unwrap_or(false);
+        if is_pressed && !was_held {
+            self.digital_just_pressed.insert(action, true);
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use cgmath::num_traits::clamp;
+use cgmath::{Deg, Point3, Rad, Vector2, Vector3};
+use std::f32::consts::PI;
+use std::time::Duration;
+use winit::event::{DeviceEvent, MouseScrollDelta};
+
+use crate::camera::{Camera, CAMERA_UP_VECTOR, OPENGL_TO_WGPU_MATRIX};
+
+const ORBIT_LOOK_SENSITIVITY: f32 = 0.005;
+const ORBIT_ZOOM_SENSITIVITY: f32 = 1.0;
+const MIN_RADIUS: f32 = 1.0;
+const MAX_RADIUS: f32 = 200.0;
+
+pub struct OrbitCamera {
+    focus: Point3<f32>,
+    radius: f32,
+    yaw: Rad<f32>,
+    pitch: Rad<f32>,
+    aspect: f32,
+    fovy: Deg<f32>,
+    znear: f32,
+    zfar: f32,
+    look_sensitivity: Vector2<f32>,
+    is_rotation_enabled: bool,
+}
+
+impl OrbitCamera {
+    pub fn new(aspect_ratio: f32) -> Self {
+        Self::new_with_focus(aspect_ratio, Point3::new(0.0, 0.0, 0.0), 15.0)
+    }
+
+    pub fn new_with_focus(aspect_ratio: f32, focus: Point3<f32>, radius: f32) -> Self {
+        Self {
+            focus,
+            radius,
+            yaw: Rad(0.0),
+            pitch: Rad(0.3),
+            aspect: aspect_ratio,
+            fovy: Deg(45.0),
+            znear: 0.1,
+            zfar: 100.0,
+            look_sensitivity: Vector2::new(ORBIT_LOOK_SENSITIVITY, ORBIT_LOOK_SENSITIVITY),
+            is_rotation_enabled: false,
+        }
+    }
+
+    fn dir(&self) -> Vector3<f32> {
+        Vector3::new(
+            self.pitch.0.cos() * self.yaw.0.cos(),
+            self.pitch.0.sin(),
+            self.pitch.0.cos() * self.yaw.0.sin(),
+        )
+    }
+
+    fn eye(&self) -> Point3<f32> {
+        self.focus + self.dir() * self.radius
+    }
+
+    fn rotate(&mut self, delta_x: f32, delta_y: f32) {
+        self.yaw += Rad(self.look_sensitivity.x * -delta_x);
+        self.pitch += Rad(self.look_sensitivity.y * delta_y);
+        self.pitch = clamp(self.pitch, Rad(-PI / 2.0 + 0.0001), Rad(PI / 2.0 - 0.0001));
+    }
+
+    fn zoom(&mut self, amount: f32) {
+        self.radius = clamp(
+            self.radius - amount * ORBIT_ZOOM_SENSITIVITY,
+            MIN_RADIUS,
+            MAX_RADIUS,
+        );
+    }
+}
+
+impl Camera for OrbitCamera {
+    fn get_vp(&self) -> [[f32; 4]; 4] {
+        let view = cgmath::Matrix4::look_at_rh(self.eye(), self.focus, CAMERA_UP_VECTOR);
+        let proj = cgmath::perspective(self.fovy, self.aspect, self.znear, self.zfar);
+        (OPENGL_TO_WGPU_MATRIX * proj * view).into()
+    }
+
+    fn get_position(&self) -> Point3<f32> {
+        self.eye()
+    }
+
+    fn resize(&mut self, aspect: f32) {
+        self.aspect = aspect;
+    }
+
+    fn set_is_rotation_enabled(&mut self, is_enabled: bool) {
+        self.is_rotation_enabled = is_enabled;
+    }
+
+    fn update(&mut self, _dt: Duration) {}
+
+    fn process_look_input(&mut self, delta_x: f32, delta_y: f32) {
+        if self.is_rotation_enabled {
+            self.rotate(delta_x, delta_y);
+        }
+    }
+
+    fn process_device_events(&mut self, event: DeviceEvent) {
+        if let DeviceEvent::MouseWheel { delta } = event {
+            let amount = match delta {
+                MouseScrollDelta::LineDelta(_, y) => y,
+                MouseScrollDelta::PixelDelta(pos) => pos.y as f32,
+            };
+            self.zoom(amount);
+        }
+    }
+}
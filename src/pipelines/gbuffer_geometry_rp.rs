@@ -0,0 +1,212 @@
+use std::mem;
+
+use cgmath::{Matrix3, Matrix4};
+use wgpu::{Device, PipelineLayout, RenderPipeline, ShaderModule, TextureFormat};
+
+use crate::{
+    bind_group_layout_descriptors, camera_controller::CameraController, model::ModelVertex,
+};
+
+use super::{
+    render_pipeline_base::PipelineBase,
+    shader_compilation_result::{CompiledShader, PipelineRecreationResult},
+};
+
+const SHADER_SOURCE: &'static str = "src/shaders/gbuffer_geometry.wgsl";
+
+const GBUFFER_ALBEDO_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba8Unorm;
+const GBUFFER_NORMAL_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba16Float;
+const GBUFFER_POSITION_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba32Float;
+
+/// Per-instance data consumed by the gbuffer geometry shader, one entry per mesh copy.
+#[repr(C)]
+#[derive(Debug, Copy, Clone, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct InstanceRaw {
+    pub model: [[f32; 4]; 4],
+    pub normal: [[f32; 3]; 3],
+}
+
+impl InstanceRaw {
+    pub fn from_model_matrix(model: Matrix4<f32>) -> Self {
+        let normal = Matrix3::from_cols(
+            model.x.truncate(),
+            model.y.truncate(),
+            model.z.truncate(),
+        );
+
+        Self {
+            model: model.into(),
+            normal: normal.into(),
+        }
+    }
+
+    fn desc() -> wgpu::VertexBufferLayout<'static> {
+        wgpu::VertexBufferLayout {
+            array_stride: mem::size_of::<InstanceRaw>() as wgpu::BufferAddress,
+            step_mode: wgpu::VertexStepMode::Instance,
+            attributes: &[
+                wgpu::VertexAttribute {
+                    offset: 0,
+                    shader_location: 5,
+                    format: wgpu::VertexFormat::Float32x4,
+                },
+                wgpu::VertexAttribute {
+                    offset: mem::size_of::<[f32; 4]>() as wgpu::BufferAddress,
+                    shader_location: 6,
+                    format: wgpu::VertexFormat::Float32x4,
+                },
+                wgpu::VertexAttribute {
+                    offset: mem::size_of::<[f32; 4]>() as wgpu::BufferAddress * 2,
+                    shader_location: 7,
+                    format: wgpu::VertexFormat::Float32x4,
+                },
+                wgpu::VertexAttribute {
+                    offset: mem::size_of::<[f32; 4]>() as wgpu::BufferAddress * 3,
+                    shader_location: 8,
+                    format: wgpu::VertexFormat::Float32x4,
+                },
+                wgpu::VertexAttribute {
+                    offset: mem::size_of::<[[f32; 4]; 4]>() as wgpu::BufferAddress,
+                    shader_location: 9,
+                    format: wgpu::VertexFormat::Float32x3,
+                },
+                wgpu::VertexAttribute {
+                    offset: mem::size_of::<[[f32; 4]; 4]>() as wgpu::BufferAddress
+                        + mem::size_of::<[f32; 3]>() as wgpu::BufferAddress,
+                    shader_location: 10,
+                    format: wgpu::VertexFormat::Float32x3,
+                },
+                wgpu::VertexAttribute {
+                    offset: mem::size_of::<[[f32; 4]; 4]>() as wgpu::BufferAddress
+                        + mem::size_of::<[f32; 3]>() as wgpu::BufferAddress * 2,
+                    shader_location: 11,
+                    format: wgpu::VertexFormat::Float32x3,
+                },
+            ],
+        }
+    }
+}
+
+pub struct GBufferGeometryRP {
+    render_pipeline: RenderPipeline,
+    shader_modification_time: u64,
+    depth_format: TextureFormat,
+}
+
+impl PipelineBase for GBufferGeometryRP {}
+
+impl GBufferGeometryRP {
+    fn create_render_pipeline(
+        device: &Device,
+        shader: &ShaderModule,
+        depth_format: TextureFormat,
+        render_pipeline_layout: &PipelineLayout,
+    ) -> RenderPipeline {
+        device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("GBuffer geometry render pipeline"),
+            layout: Some(render_pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: shader,
+                entry_point: "vs_main",
+                buffers: &[ModelVertex::desc(), InstanceRaw::desc()],
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: shader,
+                entry_point: "fs_main",
+                targets: &[
+                    Some(GBUFFER_ALBEDO_FORMAT.into()),
+                    Some(GBUFFER_NORMAL_FORMAT.into()),
+                    Some(GBUFFER_POSITION_FORMAT.into()),
+                ],
+            }),
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleList,
+                cull_mode: Some(wgpu::Face::Back),
+                ..Default::default()
+            },
+            depth_stencil: Some(wgpu::DepthStencilState {
+                format: depth_format,
+                depth_write_enabled: true,
+                depth_compare: wgpu::CompareFunction::Less,
+                stencil: wgpu::StencilState::default(),
+                bias: wgpu::DepthBiasState::default(),
+            }),
+            multisample: wgpu::MultisampleState::default(),
+            multiview: None,
+        })
+    }
+
+    fn create_pipeline_layout(device: &Device) -> PipelineLayout {
+        device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("GBuffer Geometry Render Pipeline Layout"),
+            bind_group_layouts: &[
+                &device.create_bind_group_layout(&bind_group_layout_descriptors::CAMERA),
+                &device.create_bind_group_layout(&bind_group_layout_descriptors::TEXTURE),
+            ],
+            push_constant_ranges: &[],
+        })
+    }
+
+    pub async fn new(device: &Device, depth_format: TextureFormat) -> anyhow::Result<Self> {
+        let shader = Self::compile_shader_if_needed(SHADER_SOURCE, device).await?;
+        Result::Ok(Self::new_internal(&shader, device, depth_format))
+    }
+
+    fn new_internal(shader: &CompiledShader, device: &Device, depth_format: TextureFormat) -> Self {
+        let render_pipeline_layout = Self::create_pipeline_layout(device);
+
+        let render_pipeline = Self::create_render_pipeline(
+            device,
+            &shader.shader_module,
+            depth_format,
+            &render_pipeline_layout,
+        );
+
+        Self {
+            render_pipeline,
+            shader_modification_time: shader.last_write_time,
+            depth_format,
+        }
+    }
+
+    pub async fn try_recompile_shader(&self, device: &Device) -> PipelineRecreationResult<Self> {
+        if !Self::need_recompile_shader(SHADER_SOURCE, self.shader_modification_time).await {
+            return PipelineRecreationResult::AlreadyUpToDate;
+        }
+
+        match Self::compile_shader_if_needed(SHADER_SOURCE, device).await {
+            Ok(compiled_shader) => PipelineRecreationResult::Success(Self::new_internal(
+                &compiled_shader,
+                device,
+                self.depth_format,
+            )),
+            Err(error) => PipelineRecreationResult::Failed(error),
+        }
+    }
+
+    /// Draws `instance_count` copies of the mesh given by `vertex_buffer` and `index_buffer`
+    /// in one `draw_indexed` call, reading each
+    /// instance's model (and normal) matrix from `instance_buffer`.
+    pub fn render<'a>(
+        &'a self,
+        render_pass: &mut wgpu::RenderPass<'a>,
+        camera_controller: &'a CameraController,
+        texture_bind_group: &'a wgpu::BindGroup,
+        vertex_buffer: &'a wgpu::Buffer,
+        index_buffer: &'a wgpu::Buffer,
+        num_indices: u32,
+        instance_buffer: &'a wgpu::Buffer,
+        instance_count: u32,
+    ) {
+        render_pass.set_pipeline(&self.render_pipeline);
+
+        render_pass.set_bind_group(0, &camera_controller.bind_group, &[]);
+        render_pass.set_bind_group(1, texture_bind_group, &[]);
+
+        render_pass.set_vertex_buffer(0, vertex_buffer.slice(..));
+        render_pass.set_vertex_buffer(1, instance_buffer.slice(..));
+        render_pass.set_index_buffer(index_buffer.slice(..), wgpu::IndexFormat::Uint32);
+
+        render_pass.draw_indexed(0..num_indices, 0, 0..instance_count);
+    }
+}
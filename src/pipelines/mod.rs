@@ -7,7 +7,7 @@ mod shadow_rp;
 mod skybox_rp;
 
 pub use forward_rp::ForwardRP;
-pub use gbuffer_geometry_rp::GBufferGeometryRP;
+pub use gbuffer_geometry_rp::{GBufferGeometryRP, InstanceRaw};
 pub use main_rp::MainRP;
 pub use shader_compilation_result::PipelineRecreationResult;
 pub use shadow_rp::ShadowRP;
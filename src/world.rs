@@ -0,0 +1,91 @@
+use cgmath::{EuclideanSpace, Matrix4, Point3, Quaternion, Rad, Rotation3};
+use wgpu::util::DeviceExt;
+
+use crate::camera_controller::CameraController;
+use crate::model::Model;
+use crate::pipelines::{GBufferGeometryRP, InstanceRaw};
+use crate::renderer::Renderer;
+
+const NUM_PROP_INSTANCES: usize = 200;
+
+struct PropInstance {
+    position: Point3<f32>,
+    rotation: Quaternion<f32>,
+}
+
+impl PropInstance {
+    fn to_raw(&self) -> InstanceRaw {
+        InstanceRaw::from_model_matrix(
+            Matrix4::from_translation(self.position.to_vec()) * Matrix4::from(self.rotation),
+        )
+    }
+}
+
+pub struct World {
+    gbuffer_geometry_rp: GBufferGeometryRP,
+    prop_model: Model,
+    prop_instances: Vec<PropInstance>,
+    prop_instance_buffer: wgpu::Buffer,
+}
+
+impl World {
+    pub async fn new(renderer: &Renderer) -> Self {
+        let gbuffer_geometry_rp = GBufferGeometryRP::new(&renderer.device, renderer.depth_format)
+            .await
+            .unwrap();
+        let prop_model = Model::load(&renderer.device, &renderer.queue, "res/prop.obj")
+            .await
+            .unwrap();
+
+        let prop_instances = Self::scatter_props(NUM_PROP_INSTANCES);
+        let prop_instance_buffer = Self::build_instance_buffer(&renderer.device, &prop_instances);
+
+        Self {
+            gbuffer_geometry_rp,
+            prop_model,
+            prop_instances,
+            prop_instance_buffer,
+        }
+    }
+
+    fn scatter_props(count: usize) -> Vec<PropInstance> {
+        (0..count)
+            .map(|i| {
+                let angle = i as f32 * 0.618034 * std::f32::consts::TAU;
+                let radius = (i as f32).sqrt() * 2.0;
+
+                PropInstance {
+                    position: Point3::new(radius * angle.cos(), 0.0, radius * angle.sin()),
+                    rotation: Quaternion::from_angle_y(Rad(angle)),
+                }
+            })
+            .collect()
+    }
+
+    fn build_instance_buffer(device: &wgpu::Device, instances: &[PropInstance]) -> wgpu::Buffer {
+        let raw: Vec<InstanceRaw> = instances.iter().map(PropInstance::to_raw).collect();
+
+        device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Prop Instance Buffer"),
+            contents: bytemuck::cast_slice(&raw),
+            usage: wgpu::BufferUsages::VERTEX,
+        })
+    }
+
+    pub fn render<'a>(
+        &'a self,
+        render_pass: &mut wgpu::RenderPass<'a>,
+        camera_controller: &'a CameraController,
+    ) {
+        self.gbuffer_geometry_rp.render(
+            render_pass,
+            camera_controller,
+            &self.prop_model.material_bind_group,
+            &self.prop_model.vertex_buffer,
+            &self.prop_model.index_buffer,
+            self.prop_model.num_indices,
+            &self.prop_instance_buffer,
+            self.prop_instances.len() as u32,
+        );
+    }
+}